@@ -32,6 +32,14 @@ where
     iters: Vec<MultiProductIter<I>>,
     /// It is `None` at the beginning then it holds the current item of each iterator.
     cur: Option<Vec<I::Item>>,
+    /// Mirrors `cur`, but for the item produced from the back end, via `next_back`.
+    cur_back: Option<Vec<I::Item>>,
+    /// The number of combinations not yet produced from either end, once known.
+    ///
+    /// Stays `None` until `next_back`'s first call computes it (which requires every
+    /// factor to be an `ExactSizeIterator`); `next`, `nth` and `next_back` all consult
+    /// and decrement it afterwards so the two ends agree on exactly where they meet.
+    remaining: Option<usize>,
 }
 
 impl<I> std::fmt::Debug for MultiProduct<I>
@@ -47,7 +55,7 @@ where
     I: Iterator + Clone + std::fmt::Debug,
     I::Item: Clone + std::fmt::Debug,
 {
-    debug_fmt_fields!(MultiProductInner, iters, cur);
+    debug_fmt_fields!(MultiProductInner, iters, cur, cur_back, remaining);
 }
 
 /// Create a new cartesian product iterator over an arbitrary number
@@ -66,6 +74,8 @@ where
             .map(|i| MultiProductIter::new(i.into_iter()))
             .collect(),
         cur: None,
+        cur_back: None,
+        remaining: None,
     };
     MultiProduct(Some(inner))
 }
@@ -78,6 +88,8 @@ where
     I::Item: Clone,
 {
     iter: I,
+    /// Mirrors `iter`, but counting down from the back end, via `next_back`.
+    iter_back: I,
     iter_orig: I,
 }
 
@@ -89,6 +101,7 @@ where
     fn new(iter: I) -> Self {
         Self {
             iter: iter.clone(),
+            iter_back: iter.clone(),
             iter_orig: iter,
         }
     }
@@ -104,43 +117,61 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         // This fuses the iterator.
         let inner = self.0.as_mut()?;
-        match &mut inner.cur {
+        if inner.remaining == Some(0) {
+            // `next_back` has already produced everything up to here.
+            self.0 = None;
+            return None;
+        }
+        let next = match &mut inner.cur {
             Some(values) => {
                 debug_assert!(!inner.iters.is_empty());
                 // Find (from the right) a non-finished iterator and
                 // reset the finished ones encountered.
+                let mut found = None;
                 for (iter, item) in inner.iters.iter_mut().zip(values.iter_mut()).rev() {
                     if let Some(new) = iter.iter.next() {
                         *item = new;
-                        return Some(values.clone());
+                        found = Some(values.clone());
+                        break;
                     } else {
                         iter.iter = iter.iter_orig.clone();
                         // `cur` is not none so the untouched `iter_orig` can not be empty.
                         *item = iter.iter.next().unwrap();
                     }
                 }
-                // The iterator ends.
-                self.0 = None;
-                None
+                found
             }
             // Only the first time.
             None => {
                 let next: Option<Vec<_>> = inner.iters.iter_mut().map(|i| i.iter.next()).collect();
-                if next.is_none() || inner.iters.is_empty() {
-                    // This cartesian product had at most one item to generate and now ends.
-                    self.0 = None;
-                } else {
+                if next.is_some() && !inner.iters.is_empty() {
                     inner.cur = next.clone();
                 }
                 next
             }
+        };
+        if next.is_some() {
+            if let Some(remaining) = inner.remaining.as_mut() {
+                *remaining -= 1;
+            }
+        }
+        if next.is_none() || inner.iters.is_empty() {
+            // The iterator ends (or, for the zero-factor product, had at most one item
+            // to generate and now ends too).
+            self.0 = None;
         }
+        next
     }
 
     fn count(self) -> usize {
         match self.0 {
             None => 0, // The cartesian product has ended.
-            Some(MultiProductInner { iters, cur }) => {
+            // `next_back` has already pinned down exactly how much is left.
+            Some(MultiProductInner {
+                remaining: Some(remaining),
+                ..
+            }) => remaining,
+            Some(MultiProductInner { iters, cur, .. }) => {
                 if cur.is_none() {
                     // The iterator is fresh so the count is the product of the length of each iterator:
                     // - If one of them is empty, stop counting.
@@ -172,7 +203,12 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         match &self.0 {
             None => (0, Some(0)), // The cartesian product has ended.
-            Some(MultiProductInner { iters, cur }) => {
+            // `next_back` has already pinned down exactly how much is left.
+            Some(MultiProductInner {
+                remaining: Some(remaining),
+                ..
+            }) => (*remaining, Some(*remaining)),
+            Some(MultiProductInner { iters, cur, .. }) => {
                 if cur.is_none() {
                     iters
                         .iter()
@@ -192,7 +228,16 @@ where
     }
 
     fn last(self) -> Option<Self::Item> {
-        let MultiProductInner { iters, cur } = self.0?;
+        let MultiProductInner {
+            iters,
+            cur,
+            remaining,
+            ..
+        } = self.0?;
+        if remaining == Some(0) {
+            // `next_back` has already produced everything up to here.
+            return None;
+        }
         // Collect the last item of each iterator of the product.
         if let Some(values) = cur {
             let mut count = iters.len();
@@ -225,3 +270,358 @@ where
     I::Item: Clone,
 {
 }
+
+impl<I> MultiProduct<I>
+where
+    I: ExactSizeIterator + Clone,
+    I::Item: Clone,
+{
+    /// Specialized `nth` for when every factor's length is known: rather than stepping
+    /// through `n` combinations one at a time, treat the product as a fixed-size,
+    /// mixed-radix counting space and jump each factor straight to its target digit.
+    ///
+    /// Inherent methods are preferred over trait methods during method resolution, so
+    /// this shadows [`Iterator::nth`]'s default (step-by-step) implementation whenever
+    /// `I` happens to be `ExactSizeIterator`, and is simply absent otherwise.
+    pub fn nth(&mut self, n: usize) -> Option<<Self as Iterator>::Item> {
+        let inner = self.0.as_mut()?;
+        if let Some(remaining) = inner.remaining {
+            if remaining <= n {
+                // `next_back` has already produced everything from this point on.
+                self.0 = None;
+                return None;
+            }
+        }
+        if inner.iters.is_empty() {
+            // The product of zero factors has exactly one element: the empty vector.
+            self.0 = None;
+            return (n == 0).then(Vec::new);
+        }
+        if inner.iters.iter().any(|iter| iter.iter_orig.len() == 0) {
+            // An empty factor makes the whole product empty.
+            self.0 = None;
+            return None;
+        }
+        let overflowed = match &mut inner.cur {
+            Some(values) => {
+                // `nth(n)` skips `n` combinations past the current one, so the rightmost
+                // factor needs to move `n + 1` digits forward; once a factor absorbs the
+                // whole carry (and doesn't itself wrap), every more significant factor to
+                // its left is untouched and keeps its current digit.
+                match n.checked_add(1) {
+                    None => true, // `n == usize::MAX` is always past the end.
+                    Some(mut carry) => {
+                        // Whether some factor's digit arithmetic overflowed `usize`; since the
+                        // whole product's size fits in a `usize` (as elsewhere in this file),
+                        // an overflow here can only mean the target index is past the end.
+                        let mut overflowed = false;
+                        for (iter, value) in inner.iters.iter_mut().zip(values.iter_mut()).rev() {
+                            if carry == 0 {
+                                break;
+                            }
+                            let factor_len = iter.iter_orig.len();
+                            // `iter.iter` is positioned to produce the digit right after this one.
+                            let offset = factor_len - iter.iter.len();
+                            let total = match offset.checked_sub(1).and_then(|o| o.checked_add(carry)) {
+                                Some(total) => total,
+                                None => {
+                                    overflowed = true;
+                                    break;
+                                }
+                            };
+                            let digit = total % factor_len;
+                            carry = total / factor_len;
+                            // `digit` is a valid index into `factor_len` items, so this can't miss.
+                            *value = if carry == 0 {
+                                // Still within this factor's current cycle: advance in place.
+                                iter.iter.nth(digit - offset)
+                            } else {
+                                // This factor wraps at least once: restart it from `iter_orig`.
+                                iter.iter = iter.iter_orig.clone();
+                                iter.iter.nth(digit)
+                            }
+                            .unwrap();
+                        }
+                        overflowed || carry != 0
+                    }
+                }
+            }
+            None => {
+                // Nothing has been produced yet: every factor starts from `iter_orig`, so
+                // `n` decomposes directly into mixed-radix digits with no prior offset.
+                let mut carry = n;
+                let mut values = Vec::with_capacity(inner.iters.len());
+                for iter in inner.iters.iter_mut().rev() {
+                    let factor_len = iter.iter_orig.len();
+                    let digit = carry % factor_len;
+                    carry /= factor_len;
+                    // `digit` is a valid index into `factor_len` items, so this can't miss.
+                    values.push(iter.iter.nth(digit).unwrap());
+                }
+                let overflowed = carry != 0;
+                if !overflowed {
+                    values.reverse();
+                    inner.cur = Some(values);
+                }
+                overflowed
+            }
+        };
+        if overflowed {
+            self.0 = None;
+            None
+        } else {
+            if let Some(remaining) = inner.remaining.as_mut() {
+                // `n` combinations were skipped, plus the one produced.
+                *remaining -= n + 1;
+            }
+            inner.cur.clone()
+        }
+    }
+}
+
+impl<I> MultiProduct<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator + Clone,
+    I::Item: Clone,
+{
+    /// Specialized `last` for when `next_back` is available: the last remaining
+    /// combination is exactly the one `next_back` would produce first, so this shadows
+    /// this type's [`Iterator::last`] (which only notices prior back-consumption once
+    /// `next_back` has produced everything, and would otherwise re-derive the product's
+    /// natural end, re-yielding whatever `next_back` already took).
+    pub fn last(mut self) -> Option<<Self as Iterator>::Item> {
+        self.next_back()
+    }
+}
+
+impl<I> DoubleEndedIterator for MultiProduct<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator + Clone,
+    I::Item: Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // This fuses the iterator.
+        let inner = self.0.as_mut()?;
+        if inner.remaining.is_none() {
+            // First time consuming from the back: compute how many combinations are left,
+            // so that this and future calls to `next`/`nth`/`next_back` agree on exactly
+            // where the two ends meet.
+            let total: usize = inner.iters.iter().map(|iter| iter.iter_orig.len()).product();
+            let produced_from_front = match &inner.cur {
+                Some(_) => {
+                    // The absolute, `0`-based index of the current combination, found by
+                    // reading each factor's current digit as a mixed-radix number.
+                    let index = inner.iters.iter().fold(0, |index, iter| {
+                        let factor_len = iter.iter_orig.len();
+                        let digit = factor_len - iter.iter.len() - 1;
+                        index * factor_len + digit
+                    });
+                    index + 1
+                }
+                None => 0,
+            };
+            inner.remaining = Some(total.saturating_sub(produced_from_front));
+        }
+        if inner.remaining == Some(0) {
+            self.0 = None;
+            return None;
+        }
+        let next_back = match &mut inner.cur_back {
+            Some(values) => {
+                debug_assert!(!inner.iters.is_empty());
+                // Find (from the right) a non-finished iterator and
+                // reset the finished ones encountered.
+                let mut found = None;
+                for (iter, item) in inner.iters.iter_mut().zip(values.iter_mut()).rev() {
+                    if let Some(new) = iter.iter_back.next_back() {
+                        *item = new;
+                        found = Some(values.clone());
+                        break;
+                    } else {
+                        iter.iter_back = iter.iter_orig.clone();
+                        // `remaining` is not `0` so the untouched `iter_orig` can not be empty.
+                        *item = iter.iter_back.next_back().unwrap();
+                    }
+                }
+                found
+            }
+            // Only the first time.
+            None => {
+                let next_back: Option<Vec<_>> = inner
+                    .iters
+                    .iter_mut()
+                    .map(|iter| iter.iter_back.next_back())
+                    .collect();
+                if next_back.is_some() {
+                    inner.cur_back = next_back.clone();
+                }
+                next_back
+            }
+        };
+        if let Some(remaining) = inner.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        if next_back.is_none() {
+            self.0 = None;
+        }
+        next_back
+    }
+}
+
+#[cfg(test)]
+mod double_ended_tests {
+    use super::multi_cartesian_product;
+
+    fn combos(factors: &[Vec<i32>]) -> Vec<Vec<i32>> {
+        factors.iter().fold(vec![Vec::new()], |acc, factor| {
+            acc.iter()
+                .flat_map(|prefix| {
+                    factor.iter().map(move |&x| {
+                        let mut v = prefix.clone();
+                        v.push(x);
+                        v
+                    })
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn next_back_matches_reference_in_reverse() {
+        let factors = vec![vec![1, 2, 3], vec![10, 20], vec![100, 200, 300]];
+        let mut expected = combos(&factors);
+        expected.reverse();
+        let mut mp = multi_cartesian_product(factors.iter().cloned().map(Vec::into_iter));
+        let mut got = Vec::new();
+        while let Some(values) = mp.next_back() {
+            got.push(values);
+        }
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn meets_in_the_middle_without_double_yielding() {
+        let factors = vec![vec![1, 2, 3], vec![10, 20], vec![100, 200, 300]];
+        let expected = combos(&factors);
+        let mut mp = multi_cartesian_product(factors.iter().cloned().map(Vec::into_iter));
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        loop {
+            match mp.next() {
+                Some(values) => front.push(values),
+                None => break,
+            }
+            match mp.next_back() {
+                Some(values) => back.push(values),
+                None => break,
+            }
+        }
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, expected);
+    }
+
+    #[test]
+    fn last_after_next_back_is_not_a_duplicate() {
+        let mut mp = multi_cartesian_product(vec![vec![0, 1, 2]].into_iter());
+        assert_eq!(mp.next_back(), Some(vec![2]));
+        assert_eq!(mp.last(), Some(vec![1]));
+    }
+
+    #[test]
+    fn count_and_size_hint_after_mixed_consumption() {
+        let factors = vec![vec![1, 2, 3], vec![10, 20], vec![100, 200, 300]];
+        let total = combos(&factors).len();
+        let mut mp = multi_cartesian_product(factors.iter().cloned().map(Vec::into_iter));
+        mp.next();
+        mp.next();
+        mp.next_back();
+        assert_eq!(mp.size_hint(), (total - 3, Some(total - 3)));
+        assert_eq!(mp.count(), total - 3);
+    }
+}
+
+#[cfg(test)]
+mod nth_tests {
+    use super::multi_cartesian_product;
+
+    fn combos(factors: &[Vec<i32>]) -> Vec<Vec<i32>> {
+        factors.iter().fold(vec![Vec::new()], |acc, factor| {
+            acc.iter()
+                .flat_map(|prefix| {
+                    factor.iter().map(move |&x| {
+                        let mut v = prefix.clone();
+                        v.push(x);
+                        v
+                    })
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn nth_fresh_matches_reference() {
+        let factors = vec![vec![1, 2, 3], vec![10, 20], vec![100, 200, 300]];
+        let expected = combos(&factors);
+        for n in 0..expected.len() + 1 {
+            let mut mp = multi_cartesian_product(factors.iter().cloned().map(Vec::into_iter));
+            assert_eq!(mp.nth(n), expected.get(n).cloned());
+        }
+    }
+
+    #[test]
+    fn nth_started_matches_reference() {
+        let factors = vec![vec![1, 2, 3], vec![10, 20], vec![100, 200, 300]];
+        let expected = combos(&factors);
+        for start in 0..3 {
+            for n in 0..expected.len() + 1 {
+                let mut mp = multi_cartesian_product(factors.iter().cloned().map(Vec::into_iter));
+                for _ in 0..start {
+                    mp.next();
+                }
+                assert_eq!(mp.nth(n), expected.get(start + n).cloned());
+            }
+        }
+    }
+
+    #[test]
+    fn nth_exact_last_and_one_past_the_end() {
+        let factors = vec![vec![1, 2], vec![10, 20]];
+        let expected = combos(&factors);
+        let mut mp = multi_cartesian_product(factors.iter().cloned().map(Vec::into_iter));
+        assert_eq!(mp.nth(expected.len() - 1), expected.last().cloned());
+        let mut mp = multi_cartesian_product(factors.iter().cloned().map(Vec::into_iter));
+        assert_eq!(mp.nth(expected.len()), None);
+    }
+
+    #[test]
+    fn nth_empty_factor_is_empty_product() {
+        let factors = vec![vec![1, 2, 3], Vec::<i32>::new()];
+        let mut mp = multi_cartesian_product(factors.into_iter().map(Vec::into_iter));
+        assert_eq!(mp.nth(0), None);
+    }
+
+    #[test]
+    fn nth_max_after_next_does_not_overflow_or_reyield() {
+        let mut mp = multi_cartesian_product(vec![vec![1, 2, 3]].into_iter());
+        assert_eq!(mp.next(), Some(vec![1]));
+        assert_eq!(mp.nth(usize::MAX), None);
+    }
+
+    #[test]
+    fn nth_near_max_after_two_next_calls_does_not_overflow_or_reyield() {
+        // After two `next()` calls, the rightmost factor's `offset` is `2`, so `carry`
+        // reaches `usize::MAX` from `n == usize::MAX - 1`, one step short of the shortcut
+        // that only special-cases `n == usize::MAX` itself.
+        let mut mp = multi_cartesian_product(vec![vec![1, 2, 3, 4, 5]].into_iter());
+        assert_eq!(mp.next(), Some(vec![1]));
+        assert_eq!(mp.next(), Some(vec![2]));
+        assert_eq!(mp.nth(usize::MAX - 1), None);
+
+        let factors = vec![vec![1, 2, 3], vec![10, 20]];
+        let mut mp = multi_cartesian_product(factors.into_iter().map(Vec::into_iter));
+        assert_eq!(mp.next(), Some(vec![1, 10]));
+        assert_eq!(mp.next(), Some(vec![1, 20]));
+        assert_eq!(mp.nth(usize::MAX - 1), None);
+    }
+}